@@ -0,0 +1,55 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Always,
+    Never,
+    Auto,
+}
+
+impl ColorMode {
+    pub fn parse(mode: &str) -> Option<ColorMode> {
+        match mode {
+            "always" => Some(ColorMode::Always),
+            "never" => Some(ColorMode::Never),
+            "auto" => Some(ColorMode::Auto),
+            _ => None,
+        }
+    }
+}
+
+// `auto` colors only when stderr is a terminal and NO_COLOR is not set
+pub fn init(mode: ColorMode) {
+    let enabled = match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            !no_color && io::stderr().is_terminal()
+        }
+    };
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Drop-in for colorex::Colorize that respects the --color mode
+pub trait Colorize {
+    fn color(self, color: &str) -> String;
+}
+
+impl Colorize for &str {
+    fn color(self, color: &str) -> String {
+        if is_enabled() {
+            colorex::Colorize::color(self, color)
+        } else {
+            self.to_string()
+        }
+    }
+}
@@ -1 +1,231 @@
-// error.rs
\ No newline at end of file
+use std::fmt;
+use crate::color::Colorize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveErrorKind {
+    UnexpectedCharacter,
+    UnterminatedString,
+    InvalidByteString,
+    IntegerLiteralOverflow,
+    InvalidNumberLiteral,
+    UnterminatedComment,
+    LoopControlOutsideLoop,
+    NestingTooDeep,
+    SyntaxError,
+    FormatArgumentMismatch,
+}
+
+// Central registry of diagnostic kinds: (kind, code, title)
+// Codes are stable, never reuse or renumber an existing entry.
+// E0xxx: lexer, E1xxx: parser and verification
+pub const ERROR_CODES: &[(WaveErrorKind, &str, &str)] = &[
+    (WaveErrorKind::UnexpectedCharacter, "E0001", "unexpected character"),
+    (WaveErrorKind::UnterminatedString, "E0002", "unterminated string literal"),
+    (WaveErrorKind::InvalidByteString, "E0003", "invalid byte string literal"),
+    (WaveErrorKind::IntegerLiteralOverflow, "E0004", "integer literal out of range"),
+    (WaveErrorKind::InvalidNumberLiteral, "E0005", "invalid number literal"),
+    (WaveErrorKind::UnterminatedComment, "E0006", "unterminated block comment"),
+    (WaveErrorKind::LoopControlOutsideLoop, "E1001", "break or continue outside of a loop"),
+    (WaveErrorKind::NestingTooDeep, "E1002", "code is too deeply nested"),
+    (WaveErrorKind::SyntaxError, "E1003", "syntax error"),
+    (WaveErrorKind::FormatArgumentMismatch, "E1004", "format string argument count mismatch"),
+];
+
+// Long-form explanations shown by `wave --explain <code>`
+const EXPLANATIONS: &[(&str, &str)] = &[
+    ("E0001", "\
+A character appeared that does not start any Wave token.
+
+Erroneous code example:
+
+    var total: i32 = 5 $ 3;
+
+Remove the character, or replace it with the intended operator:
+
+    var total: i32 = 5 + 3;"),
+    ("E0002", "\
+A string literal was opened with '\"' but never closed before the end of the file.
+
+Erroneous code example:
+
+    println(\"Hello);
+
+Add the closing quote:
+
+    println(\"Hello\");"),
+    ("E0003", "\
+A byte string literal must contain an even number of hexadecimal digits,
+two per byte.
+
+Erroneous code example:
+
+    var magic = x\"CAF\";
+
+Write every byte as two hex digits:
+
+    var magic = x\"CAFE\";"),
+    ("E0004", "\
+An integer literal is larger than the biggest value an i64 can hold
+(9223372036854775807).
+
+Erroneous code example:
+
+    var big: i64 = 9_223_372_036_854_775_808;
+
+Use a smaller value, or a float literal if precision loss is acceptable."),
+    ("E0005", "\
+A number literal is malformed. Hex literals need at least one digit after
+'0x', and hex floats need a 'p' exponent.
+
+Erroneous code example:
+
+    var mask: i32 = 0x;
+    var half: f64 = 0x1.0;
+
+Add the missing digits or exponent:
+
+    var mask: i32 = 0xFF;
+    var half: f64 = 0x1.0p-1;"),
+    ("E0006", "\
+A block comment was opened with '/*' but never closed with '*/'.
+
+Erroneous code example:
+
+    /* temporary
+    fun main() { }
+
+Close the comment where it should end:
+
+    /* temporary */
+    fun main() { }"),
+    ("E1001", "\
+'break' and 'continue' only make sense inside a for, while or do-while loop.
+
+Erroneous code example:
+
+    fun main() {
+        break;
+    }
+
+Move the statement into a loop, or remove it:
+
+    fun main() {
+        while (true) {
+            break;
+        }
+    }"),
+    ("E1002", "\
+Blocks are nested deeper than the parser allows (256 levels by default).
+
+Split deeply nested code into smaller functions, or raise the limit with
+--max-nesting-depth=<n>."),
+    ("E1003", "\
+The parser expected a different token at this point, such as a missing
+parenthesis, brace or ':' in a declaration.
+
+Erroneous code example:
+
+    do {
+        i++;
+    } while i < 10;
+
+Add the missing token:
+
+    do {
+        i++;
+    } while (i < 10);"),
+    ("E1004", "\
+print, println and input need exactly one argument for every '{}'
+placeholder in their format string.
+
+Erroneous code example:
+
+    println(\"Hello {}\");
+
+Pass a value for each placeholder, or remove the placeholder:
+
+    println(\"Hello {}\", name);"),
+];
+
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(code))
+        .map(|(_, text)| *text)
+}
+
+impl WaveErrorKind {
+    fn entry(&self) -> &'static (WaveErrorKind, &'static str, &'static str) {
+        ERROR_CODES
+            .iter()
+            .find(|(kind, _, _)| kind == self)
+            .expect("every WaveErrorKind must be registered in ERROR_CODES")
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.entry().1
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.entry().2
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WaveError {
+    pub kind: WaveErrorKind,
+    pub message: String,
+    pub line: usize,
+    pub column: Option<usize>,
+    pub source_line: Option<String>,
+}
+
+impl WaveError {
+    pub fn new(kind: WaveErrorKind, message: impl Into<String>, line: usize) -> Self {
+        WaveError {
+            kind,
+            message: message.into(),
+            line,
+            column: None,
+            source_line: None,
+        }
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn with_source_line(mut self, source_line: impl Into<String>) -> Self {
+        self.source_line = Some(source_line.into());
+        self
+    }
+}
+
+impl fmt::Display for WaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = format!("error[{}]", self.kind.code());
+        writeln!(f, "{}: {}", header.as_str().color("255,71,71"), self.kind.title())?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} --> line {}", gutter, self.line)?;
+
+        if let Some(source_line) = &self.source_line {
+            writeln!(f, "{} |", gutter)?;
+            writeln!(f, "{} | {}", self.line, source_line)?;
+
+            // Underline the reported column, or the whole statement when the column is unknown
+            let (start, width) = match self.column {
+                Some(column) => (column.saturating_sub(1), 1),
+                None => {
+                    let trimmed = source_line.trim_start();
+                    let indent = source_line.chars().count() - trimmed.chars().count();
+                    (indent, trimmed.trim_end().chars().count().max(1))
+                }
+            };
+            let caret = "^".repeat(width);
+            writeln!(f, "{} | {}{}", gutter, " ".repeat(start), caret.as_str().color("255,71,71"))?;
+        }
+
+        write!(f, "{} = {}", gutter, self.message)
+    }
+}
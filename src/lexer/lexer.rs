@@ -1,7 +1,9 @@
 use crate::lexer::token::*;
+use crate::error::{WaveError, WaveErrorKind};
+use serde::Serialize;
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Token {
     pub token_type: TokenType,
     pub lexeme: String,
@@ -33,6 +35,7 @@ pub struct Lexer<'a> {
     pub(crate) source: &'a str,
     pub(crate) current: usize,
     pub(crate) line: usize,
+    pub errors: Vec<WaveError>,
 }
 
 impl<'a> Lexer<'a> {
@@ -41,6 +44,7 @@ impl<'a> Lexer<'a> {
             source,
             current: 0,
             line: 1,
+            errors: Vec::new(),
         }
     }
 
@@ -53,6 +57,8 @@ impl<'a> Lexer<'a> {
         self.source.chars().nth(self.current - 1).unwrap_or('\0')
     }
 
+    // Skips whitespace and plain comments in a loop, so long runs of comments
+    // do not recurse through next_token. `///` doc comments are left for next_token.
     fn skip_whitespace(&mut self) {
         while !self.is_at_end() {
             let c = self.peek();
@@ -64,16 +70,59 @@ impl<'a> Lexer<'a> {
                     self.line += 1;
                     self.advance();
                 }
+                // Exactly three slashes start a doc comment, `////` banners are plain comments
+                '/' if self.peek_at(1) == '/' && (self.peek_at(2) != '/' || self.peek_at(3) == '/') => {
+                    self.line_comment();
+                }
+                '/' if self.peek_at(1) == '*' => {
+                    self.current += 2;
+                    self.skip_multiline_comment();
+                }
                 _ => break,
             }
         }
     }
 
     fn peek(&self) -> char {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> char {
         if self.is_at_end() {
             '\0'
         } else {
-            self.source.chars().nth(self.current).unwrap_or('\0')
+            self.source.chars().nth(self.current + offset).unwrap_or('\0')
+        }
+    }
+
+    // Records a lexing error at `position` on `line`; lexing continues so that
+    // every error in the file can be reported at once
+    fn error(&mut self, kind: WaveErrorKind, message: String, line: usize, position: usize, at_column: bool) {
+        let consumed: Vec<char> = self.source.chars().take(position).collect();
+        let line_start = consumed
+            .iter()
+            .rposition(|&c| c == '\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let source_line: String = self.source
+            .chars()
+            .skip(line_start)
+            .take_while(|&c| c != '\n')
+            .collect();
+
+        let mut error = WaveError::new(kind, message, line).with_source_line(source_line);
+        if at_column {
+            error = error.with_column(position - line_start);
+        }
+
+        self.errors.push(error);
+    }
+
+    fn error_token(&self, lexeme: String) -> Token {
+        Token {
+            token_type: TokenType::ERROR,
+            lexeme,
+            line: self.line,
         }
     }
 
@@ -100,6 +149,20 @@ impl<'a> Lexer<'a> {
             }
             tokens.push(token);
         }
+
+        // Doc comments only document a following `fun`, anywhere else they are plain comments
+        // and must not leak into conditions or call arguments
+        let mut documents_function = false;
+        for index in (0..tokens.len()).rev() {
+            match tokens[index].token_type {
+                TokenType::DocComment(_) if !documents_function => {
+                    tokens.remove(index);
+                }
+                TokenType::DocComment(_) => {}
+                TokenType::FUN => documents_function = true,
+                _ => documents_function = false,
+            }
+        }
         tokens
     }
 
@@ -181,10 +244,22 @@ impl<'a> Lexer<'a> {
                 }
             },
             '/' => {
-                Token {
-                    token_type: TokenType::DIV,
-                    lexeme: "/".to_string(),
-                    line: self.line,
+                // Plain `//` and `/* */` comments were already skipped by skip_whitespace
+                if self.peek() == '/' && self.peek_at(1) == '/' {
+                    // `///` doc comments are kept so they can be attached to declarations
+                    self.current += 2;
+                    let doc = self.line_comment();
+                    Token {
+                        token_type: TokenType::DocComment(doc.trim().to_string()),
+                        lexeme: format!("///{}", doc),
+                        line: self.line,
+                    }
+                } else {
+                    Token {
+                        token_type: TokenType::DIV,
+                        lexeme: "/".to_string(),
+                        line: self.line,
+                    }
                 }
             },
             ';' => {
@@ -392,11 +467,26 @@ impl<'a> Lexer<'a> {
                 }
             },
             '"' => {
-                let string_value = self.string();
-                Token {
-                    token_type: TokenType::STRING(string_value.clone()),
-                    lexeme: format!("\"{}\"", string_value),
-                    line: self.line,
+                match self.string() {
+                    Some(string_value) => Token {
+                        token_type: TokenType::STRING(string_value.clone()),
+                        lexeme: format!("\"{}\"", string_value),
+                        line: self.line,
+                    },
+                    None => self.error_token("\"".to_string()),
+                }
+            },
+            'x' if self.peek() == '"' => {
+                match self.byte_string() {
+                    Some(bytes) => {
+                        let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+                        Token {
+                            token_type: TokenType::ByteString(bytes),
+                            lexeme: format!("x\"{}\"", hex),
+                            line: self.line,
+                        }
+                    }
+                    None => self.error_token("x\"".to_string()),
                 }
             },
             'a'..='z' | 'A'..='Z' => {
@@ -444,6 +534,13 @@ impl<'a> Lexer<'a> {
                             line: self.line,
                         }
                     },
+                    "elif" => {
+                        Token {
+                            token_type: TokenType::ELIF,
+                            lexeme: "elif".to_string(),
+                            line: self.line,
+                        }
+                    },
                     "while" => {
                         Token {
                             token_type: TokenType::WHILE,
@@ -451,6 +548,13 @@ impl<'a> Lexer<'a> {
                             line: self.line,
                         }
                     },
+                    "do" => {
+                        Token {
+                            token_type: TokenType::DO,
+                            lexeme: "do".to_string(),
+                            line: self.line,
+                        }
+                    },
                     "for" => {
                         Token {
                             token_type: TokenType::FOR,
@@ -521,6 +625,13 @@ impl<'a> Lexer<'a> {
                             line: self.line,
                         }
                     },
+                    "break" => {
+                        Token {
+                            token_type: TokenType::BREAK,
+                            lexeme: "break".to_string(),
+                            line: self.line,
+                        }
+                    },
                     "continue" => {
                         Token {
                             token_type: TokenType::CONTINUE,
@@ -528,6 +639,27 @@ impl<'a> Lexer<'a> {
                             line: self.line,
                         }
                     },
+                    "unreachable" => {
+                        Token {
+                            token_type: TokenType::UNREACHABLE,
+                            lexeme: "unreachable".to_string(),
+                            line: self.line,
+                        }
+                    },
+                    "true" => {
+                        Token {
+                            token_type: TokenType::TRUE,
+                            lexeme: "true".to_string(),
+                            line: self.line,
+                        }
+                    },
+                    "false" => {
+                        Token {
+                            token_type: TokenType::FALSE,
+                            lexeme: "false".to_string(),
+                            line: self.line,
+                        }
+                    },
                     "print" => {
                         Token {
                             token_type: TokenType::PRINT,
@@ -762,6 +894,11 @@ impl<'a> Lexer<'a> {
                         lexeme: "u32768".to_string(),
                         line: self.line,
                     },
+                    "f16" => Token {
+                        token_type: TokenType::TypeFloat(FloatType::F16),
+                        lexeme: "f16".to_string(),
+                        line: self.line,
+                    },
                     "f32" => Token {
                         token_type: TokenType::TypeFloat(FloatType::F32),
                         lexeme: "f32".to_string(),
@@ -844,8 +981,20 @@ impl<'a> Lexer<'a> {
                     }
                 }
             },
+            '0' if matches!(self.peek(), 'x' | 'X') => self.hex_number(),
             '0'..='9' => {
-                let mut num_str = self.number().to_string(); // Converting Numbers to Strings
+                let start = self.current - 1;
+                let mut num_str = self.number();
+                if self.peek() != '.' && i64::from_str(&num_str).is_err() {
+                    self.error(
+                        WaveErrorKind::IntegerLiteralOverflow,
+                        format!("integer literal {} does not fit in i64", num_str),
+                        self.line,
+                        start + 1,
+                        true,
+                    );
+                    return self.error_token(num_str);
+                }
                 if self.peek() == '.' { // If the following characters are dots, handle mistakes
                     num_str.push('.'); // Add a dot
                     self.advance(); // turning over a mole
@@ -857,14 +1006,8 @@ impl<'a> Lexer<'a> {
 
                 // Safe handling of errors in accidental parsing
                 let token_type = match num_str.parse::<f64>() {
-                    Ok(n) => {
-                        // In case of accidental parsing
-                        if n.fract() == 0.0 { // If there's an integer part and there's no decimal part
-                            TokenType::FLOAT(n as i64 as f64)  // Processing with water purification
-                        } else {
-                            TokenType::FLOAT(n)  // Processing with Real number
-                        }
-                    }
+                    // Whole values stay as they are; casting through i64 would clamp anything past i64::MAX
+                    Ok(n) => TokenType::FLOAT(n),
                     Err(_) => {
                         TokenType::FLOAT(0.0) // Use 0.0 as default in case of parsing failure
                     }
@@ -877,8 +1020,14 @@ impl<'a> Lexer<'a> {
                 }
             },
             _ => {
-                eprintln!("[eprintln] Unexpected character: {}", c);
-                panic!("[panic] Unexpected character: {}", c);
+                self.error(
+                    WaveErrorKind::UnexpectedCharacter,
+                    format!("unexpected character '{}'", c),
+                    self.line,
+                    self.current,
+                    true,
+                );
+                self.error_token(c.to_string())
             }
         }
     }
@@ -908,25 +1057,103 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    // Consumes the rest of the line (without the newline) and returns it
+    fn line_comment(&mut self) -> String {
+        let mut comment = String::new();
+        while !self.is_at_end() && self.peek() != '\n' {
+            comment.push(self.advance());
+        }
+        comment
+    }
+
+    fn skip_multiline_comment(&mut self) {
+        let start_line = self.line;
+        let start = self.current - 2;
+
+        while !self.is_at_end() {
+            let c = self.advance();
+            if c == '\n' {
+                self.line += 1;
+            } else if c == '*' && self.match_next('/') {
+                return;
+            }
+        }
+
+        self.error(
+            WaveErrorKind::UnterminatedComment,
+            "missing closing '*/' before end of file".to_string(),
+            start_line,
+            start,
+            false,
+        );
+    }
+
     // Add string literal processing function
-    fn string(&mut self) -> String {
+    // Returns None (after recording an error) when the closing quote is missing
+    fn string(&mut self) -> Option<String> {
         if self.peek() == '"' {
             self.advance();
         }
 
+        let start_line = self.line;
+        let start = self.current;
         let mut string_literal = String::new();
 
         while !self.is_at_end() && self.peek() != '"' {
-            string_literal.push(self.advance());
+            let c = self.advance();
+            if c == '\n' {
+                self.line += 1;
+            }
+            string_literal.push(c);
         }
 
         if self.is_at_end() {
-            panic!("Unterminated string.");
+            self.error(
+                WaveErrorKind::UnterminatedString,
+                "missing closing '\"' before end of file".to_string(),
+                start_line,
+                start,
+                false,
+            );
+            return None;
         }
 
         self.advance(); // closing quote
 
-        string_literal
+        Some(string_literal)
+    }
+
+    // Byte string literal processing: x"DEADBEEF"
+    fn byte_string(&mut self) -> Option<Vec<u8>> {
+        let start = self.current;
+        let hex = self.string()?;
+
+        if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            self.error(
+                WaveErrorKind::InvalidByteString,
+                format!("invalid hex digit '{}' in x\"{}\"", c, hex),
+                self.line,
+                start,
+                false,
+            );
+            return None;
+        }
+
+        if hex.len() % 2 != 0 {
+            self.error(
+                WaveErrorKind::InvalidByteString,
+                format!("odd number of hex digits in x\"{}\"", hex),
+                self.line,
+                start,
+                false,
+            );
+            return None;
+        }
+
+        Some((0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect())
     }
 
     fn identifier(&mut self) -> String {
@@ -943,13 +1170,333 @@ impl<'a> Lexer<'a> {
         self.source[start..self.current].to_string()
     }
 
-    fn number(&mut self) -> i64 {
+    // Hexadecimal literals: 0x1FF, and C99 hex floats like 0x1.8p3 (exact f64)
+    fn hex_number(&mut self) -> Token {
         let start = self.current - 1;
-        while !self.is_at_end() && self.peek().is_numeric() {
+        self.advance(); // 'x'
+
+        let mut mantissa: u64 = 0;
+        let mut exponent: i64 = 0;
+        let mut digits = 0;
+        let mut inexact = false;
+        let mut is_float = false;
+        let mut digit_runs = vec![String::new()];
+
+        while self.peek().is_ascii_hexdigit() || self.peek() == '_' {
+            let c = self.advance();
+            digit_runs[0].push(c);
+            if let Some(digit) = c.to_digit(16) {
+                digits += 1;
+                match mantissa.checked_mul(16) {
+                    Some(shifted) => mantissa = shifted + digit as u64,
+                    None => {
+                        // Keep the leading digits and scale, the value is no longer exact
+                        exponent += 4;
+                        inexact |= digit != 0;
+                    }
+                }
+            }
+        }
+
+        if self.peek() == '.' {
+            is_float = true;
+            self.advance();
+            digit_runs.push(String::new());
+            while self.peek().is_ascii_hexdigit() || self.peek() == '_' {
+                let c = self.advance();
+                digit_runs[1].push(c);
+                if let Some(digit) = c.to_digit(16) {
+                    digits += 1;
+                    if let Some(shifted) = mantissa.checked_mul(16) {
+                        mantissa = shifted + digit as u64;
+                        exponent -= 4;
+                    } else {
+                        inexact |= digit != 0;
+                    }
+                }
+            }
+        }
+
+        let has_exponent = matches!(self.peek(), 'p' | 'P');
+        if has_exponent {
+            is_float = true;
+            self.advance();
+            let mut exponent_str = String::new();
+            if matches!(self.peek(), '+' | '-') {
+                exponent_str.push(self.advance());
+            }
+            while self.peek().is_ascii_digit() {
+                exponent_str.push(self.advance());
+            }
+            match exponent_str.parse::<i64>() {
+                Ok(value) => exponent += value,
+                Err(_) => {
+                    self.error(
+                        WaveErrorKind::InvalidNumberLiteral,
+                        "expected decimal digits after 'p' in hex float literal".to_string(),
+                        self.line,
+                        start + 1,
+                        true,
+                    );
+                    return self.error_token(self.lexeme_from(start));
+                }
+            }
+        }
+
+        let lexeme = self.lexeme_from(start);
+
+        if digits == 0 {
+            self.error(
+                WaveErrorKind::InvalidNumberLiteral,
+                format!("expected hex digits after '0x' in {}", lexeme),
+                self.line,
+                start + 1,
+                true,
+            );
+            return self.error_token(lexeme);
+        }
+
+        // Same rule as decimal literals: a separator must sit between two digits
+        let misplaced_separator = digit_runs
+            .iter()
+            .any(|run| run.starts_with('_') || run.ends_with('_') || run.contains("__"));
+        if misplaced_separator {
+            self.error(
+                WaveErrorKind::InvalidNumberLiteral,
+                format!("misplaced '_' digit separator in {}", lexeme),
+                self.line,
+                start + 1,
+                true,
+            );
+            return self.error_token(lexeme);
+        }
+
+        if is_float && !has_exponent {
+            self.error(
+                WaveErrorKind::InvalidNumberLiteral,
+                format!("hex float literal {} needs a 'p' exponent", lexeme),
+                self.line,
+                start + 1,
+                true,
+            );
+            return self.error_token(lexeme);
+        }
+
+        let token_type = if is_float {
+            let value = hex_float_value(mantissa, exponent, inexact);
+            if !value.is_finite() {
+                self.error(
+                    WaveErrorKind::InvalidNumberLiteral,
+                    format!("hex float literal {} is too large for f64", lexeme),
+                    self.line,
+                    start + 1,
+                    true,
+                );
+                return self.error_token(lexeme);
+            }
+            TokenType::FLOAT(value)
+        } else {
+            match i64::try_from(mantissa) {
+                Ok(value) if exponent == 0 => TokenType::NUMBER(value),
+                _ => {
+                    self.error(
+                        WaveErrorKind::IntegerLiteralOverflow,
+                        format!("integer literal {} does not fit in i64", lexeme),
+                        self.line,
+                        start + 1,
+                        true,
+                    );
+                    return self.error_token(lexeme);
+                }
+            }
+        };
+
+        Token {
+            token_type,
+            lexeme,
+            line: self.line,
+        }
+    }
+
+    // Scans the integer digits of a literal and strips its digit separators
+    fn number(&mut self) -> String {
+        let start = self.current - 1;
+        while !self.is_at_end() && (self.peek().is_numeric() || self.peek() == '_') {
             self.advance();
         }
 
-        let number_str = &self.source[start..self.current];
-        i64::from_str(number_str).unwrap_or_else(|_| 0)
+        // Digit separators (1_000_000) are only for readability and must sit between two digits
+        let source = self.source;
+        let raw = &source[start..self.current];
+        if raw.contains("__") || raw.ends_with('_') {
+            self.error(
+                WaveErrorKind::InvalidNumberLiteral,
+                format!("misplaced '_' digit separator in {}", raw),
+                self.line,
+                start + 1,
+                true,
+            );
+        }
+
+        raw.chars().filter(|&c| c != '_').collect()
+    }
+
+    fn lexeme_from(&self, start: usize) -> String {
+        self.source.chars().skip(start).take(self.current - start).collect()
+    }
+}
+
+
+// Rounds mantissa * 2^exponent to the nearest f64, ties to even.
+// `sticky` is set when non-zero digits were dropped past the end of the mantissa,
+// which breaks a tie in favour of rounding up.
+fn hex_float_value(mantissa: u64, exponent: i64, sticky: bool) -> f64 {
+    if mantissa == 0 {
+        return 0.0;
+    }
+
+    // Keep 53 significant bits, or fewer when the value falls into the subnormal range
+    let bits = 64 - mantissa.leading_zeros() as i64;
+    let top_exponent = exponent + bits - 1;
+    let precision = (top_exponent + 1075).min(53);
+    let shift = bits - precision;
+
+    let (significand, exponent) = if shift <= 0 {
+        (mantissa, exponent)
+    } else if shift > 64 {
+        (0, exponent + shift)
+    } else {
+        let wide = mantissa as u128;
+        let truncated = (wide >> shift) as u64;
+        let dropped = wide & ((1u128 << shift) - 1);
+        let half = 1u128 << (shift - 1);
+        let round_up = dropped > half || (dropped == half && (sticky || truncated & 1 == 1));
+        (truncated + round_up as u64, exponent + shift)
+    };
+
+    // The significand now fits in 54 bits, so scaling by powers of two is exact
+    // until the result overflows to infinity
+    let mut value = significand as f64;
+    let mut remaining = exponent;
+    while remaining > 1000 && value.is_finite() {
+        value *= 2f64.powi(1000);
+        remaining -= 1000;
+    }
+    while remaining < -1000 && value != 0.0 {
+        value *= 2f64.powi(-1000);
+        remaining += 1000;
+    }
+    value * 2f64.powi(remaining.clamp(-1100, 1100) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lex(source: &str) -> (Vec<TokenType>, Vec<WaveErrorKind>) {
+        let mut lexer = Lexer::new(source);
+        let tokens = lexer.tokenize().into_iter().map(|token| token.token_type).collect();
+        let errors = lexer.errors.iter().map(|error| error.kind).collect();
+        (tokens, errors)
+    }
+
+    #[test]
+    fn digit_separators_are_stripped() {
+        let (tokens, errors) = lex("1_000_000");
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0], TokenType::FLOAT(1_000_000.0));
+    }
+
+    #[test]
+    fn misplaced_digit_separators_are_rejected() {
+        for source in ["1__0", "10_", "1__0_"] {
+            let (_, errors) = lex(source);
+            assert_eq!(errors, vec![WaveErrorKind::InvalidNumberLiteral], "{}", source);
+        }
+    }
+
+    #[test]
+    fn integer_literal_overflow_is_rejected() {
+        let (_, errors) = lex("9_223_372_036_854_775_807");
+        assert!(errors.is_empty());
+
+        let (tokens, errors) = lex("9223372036854775808");
+        assert_eq!(errors, vec![WaveErrorKind::IntegerLiteralOverflow]);
+        assert_eq!(tokens[0], TokenType::ERROR);
+    }
+
+    #[test]
+    fn large_decimal_float_is_not_an_integer_overflow() {
+        let (tokens, errors) = lex("99999999999999999999.5");
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0], TokenType::FLOAT(99999999999999999999.5));
+    }
+
+    fn hex_float_bits(source: &str) -> u64 {
+        match lex(source) {
+            (tokens, errors) if errors.is_empty() => match tokens[0] {
+                TokenType::FLOAT(value) => value.to_bits(),
+                ref other => panic!("{} lexed as {:?}", source, other),
+            },
+            (_, errors) => panic!("{} reported {:?}", source, errors),
+        }
+    }
+
+    #[test]
+    fn hex_floats_are_exact() {
+        assert_eq!(hex_float_bits("0x1.8p3"), 12.0f64.to_bits());
+        assert_eq!(hex_float_bits("0x1p-1074"), 1);
+        assert_eq!(hex_float_bits("0x1.fffffffffffffp1023"), f64::MAX.to_bits());
+    }
+
+    #[test]
+    fn hex_floats_round_to_nearest_even() {
+        // Exactly halfway between 1.0 and the next f64, ties go to the even 1.0
+        assert_eq!(hex_float_bits("0x1.00000000000008p0"), 1.0f64.to_bits());
+        // A non-zero digit past the mantissa breaks the tie upwards
+        assert_eq!(hex_float_bits("0x1.00000000000008000000001p0"), 1.0f64.to_bits() + 1);
+        // Halfway to the smallest subnormal rounds to zero, anything above rounds up
+        assert_eq!(hex_float_bits("0x1p-1075"), 0);
+        assert_eq!(hex_float_bits("0x1.8p-1075"), 1);
+    }
+
+    #[test]
+    fn hex_digit_separators() {
+        assert_eq!(lex("0xFF_FF").0[0], TokenType::NUMBER(0xFFFF));
+        assert_eq!(hex_float_bits("0x1_0.8p3"), 132.0f64.to_bits());
+        for source in ["0x__F_", "0xF__F", "0x_F", "0xF_", "0x1_.8p3", "0x1._8p3"] {
+            let (_, errors) = lex(source);
+            assert_eq!(errors, vec![WaveErrorKind::InvalidNumberLiteral], "{}", source);
+        }
+    }
+
+    #[test]
+    fn hex_float_overflow_is_rejected() {
+        for source in ["0x1p1024", "0x1.fffffffffffff8p1023"] {
+            let (_, errors) = lex(source);
+            assert_eq!(errors, vec![WaveErrorKind::InvalidNumberLiteral], "{}", source);
+        }
+    }
+
+    #[test]
+    fn long_comment_runs_do_not_recurse() {
+        let source = "// line\n/* block */\n".repeat(500) + "x";
+        let (tokens, errors) = lex(&source);
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0], TokenType::IDENTIFIER("x".to_string()));
+    }
+
+    #[test]
+    fn banner_comments_are_not_doc_comments() {
+        let (tokens, errors) = lex("//// banner\n/// Entry point\nfun main");
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0], TokenType::DocComment("Entry point".to_string()));
+        assert_eq!(tokens[1], TokenType::FUN);
+    }
+
+    #[test]
+    fn doc_comments_only_attach_to_functions() {
+        let (tokens, _) = lex("/// a variable\nvar x: i32;\nif (a /// weird\n > 1) { }");
+        assert!(!tokens.iter().any(|token| matches!(token, TokenType::DocComment(_))));
     }
 }
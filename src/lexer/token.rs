@@ -1,6 +1,7 @@
 use std::fmt;
+use serde::Serialize;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub enum IntegerType {
     I4,
     I8,
@@ -19,7 +20,7 @@ pub enum IntegerType {
     ISZ,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub enum UnsignedIntegerType {
     U4,
     U8,
@@ -38,8 +39,9 @@ pub enum UnsignedIntegerType {
     USZ,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
 pub enum FloatType {
+    F16,
     F32,
     F64,
     F128,
@@ -99,7 +101,7 @@ impl fmt::Display for UnsignedIntegerType {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum TokenType {
     FUN,
     VAR,
@@ -107,11 +109,17 @@ pub enum TokenType {
     CONST,
     IF,
     ELSE,
+    ELIF,
     WHILE,
+    DO,
     FOR,
     IMPORT,
     RETURN,
+    BREAK,
     CONTINUE,
+    UNREACHABLE,
+    TRUE,
+    FALSE,
     INPUT,
     PRINT,
     PRINTLN,
@@ -147,6 +155,8 @@ pub enum TokenType {
     TypeString,
     IDENTIFIER(String),
     STRING(String),
+    ByteString(Vec<u8>),    // x"DEADBEEF"
+    DocComment(String),     // /// ...
     NUMBER(i64),
     FLOAT(f64),
     PLUS,                   // +
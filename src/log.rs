@@ -0,0 +1,20 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_verbose(enabled: bool) {
+    VERBOSE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+// Prints a compiler phase message to stderr, only when `--verbose` is enabled
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::log::is_verbose() {
+            eprintln!("[verbose] {}", format!($($arg)*));
+        }
+    };
+}
@@ -1,17 +1,49 @@
+#[macro_use]
+mod log;
+mod color;
 mod lexer;
 mod parser;
 mod error;
 
 use std::{env, fs, process};
-use colorex::Colorize;
-use lexer::{Lexer};
+use std::io::{self, Read};
+use crate::color::{ColorMode, Colorize};
+use lexer::{Lexer, Token};
 use crate::lexer::TokenType;
-use crate::parser::{extract_body, extract_parameters, function};
+use crate::parser::{extract_body, extract_doc_comment, extract_parameters, extract_type_params, function, is_function_name, take_errors};
+use crate::parser::ast::{ASTNode, FunctionNode};
+use crate::parser::verification::validate_loop_control;
+use crate::error::WaveError;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+#[derive(Debug, Default)]
+struct RunOptions {
+    dump_ast_json: bool,
+    dump_tokens_json: bool,
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut color_mode = ColorMode::Auto;
+    let mut args: Vec<String> = Vec::new();
+    // Global flags may appear anywhere, before or after the command
+    for arg in env::args() {
+        if arg == "-v" || arg == "--verbose" {
+            log::set_verbose(true);
+            continue;
+        }
+        match arg.strip_prefix("--color=") {
+            Some(mode) => match ColorMode::parse(mode) {
+                Some(mode) => color_mode = mode,
+                None => {
+                    eprintln!("Unknown color mode: {} (expected always, never or auto)", mode);
+                    process::exit(1);
+                }
+            },
+            None => args.push(arg),
+        }
+    }
+    color::init(color_mode);
 
     if args.len() < 2 {
         eprintln!("{} {}",
@@ -25,9 +57,24 @@ fn main() {
                   "run <file>".color("38,139,235"),
                   "Execute the specified Wave file");
 
+        eprintln!("  {}  Check the specified Wave file for errors",
+                  "check <file>".color("38,139,235"));
+
         eprintln!("  {}     {}",
                   "--version".color("38,139,235"),
                   "Show the CLI version");
+
+        eprintln!("  {}  Explain an error code",
+                  "--explain <code>".color("38,139,235"));
+
+        eprintln!("{}",
+                  "Options:".color("145,161,2"));
+
+        eprintln!("  {}  Log each compiler phase",
+                  "-v, --verbose".color("38,139,235"));
+
+        eprintln!("  {}  Color output: always, never or auto",
+                  "--color=<mode>".color("38,139,235"));
         process::exit(1);
     }
 
@@ -38,26 +85,68 @@ fn main() {
             return;
         }
         "run" => {
-            if args.len() < 3 {
-                eprintln!("{} {}",
-                          "Usage:".color("255,71,71"),
-                          "wave run <file>");
+            let (file_path, options) = parse_file_args(&args[2..], "wave run [--verbose] [--max-errors=<n>] [--dump-ast=json] [--dump-tokens=json] <file | ->");
+            run_wave_file(file_path, &options);
+        }
+        "check" => {
+            let (file_path, options) = parse_file_args(&args[2..], "wave check [--verbose] [--max-errors=<n>] <file | ->");
+            // check never produces output to dump, so refuse rather than silently ignoring the flag
+            if options.dump_ast_json || options.dump_tokens_json {
+                eprintln!("{} use 'wave run' to dump the AST or tokens",
+                          "--dump-ast and --dump-tokens are not supported by check:".color("255,71,71"));
                 process::exit(1);
             }
-
-            let file_path = &args[2];
-            run_wave_file(file_path);
+            check_wave_file(file_path);
+        }
+        "--explain" => {
+            let code = match args.get(2) {
+                Some(code) => code,
+                None => {
+                    eprintln!("{} wave --explain <code>",
+                              "Usage:".color("255,71,71"));
+                    process::exit(1);
+                }
+            };
+            match error::explain(code) {
+                Some(text) => println!("{}", text),
+                None => {
+                    eprintln!("{} {}",
+                              "Unknown error code:".color("255,71,71"),
+                              code);
+                    process::exit(1);
+                }
+            }
         }
         "help" => {
             println!("{}", "Options:".color("145,161,2"));
-            println!("      {}       {}\n",
+            println!("      {}       {}",
                      "run <file>".color("38,139,235"),
                      "Run the Wave code.");
+            println!("      {}            Run Wave code read from stdin.",
+                     "run -".color("38,139,235"));
+            println!("      {}     Lex and parse the Wave code without running it.\n",
+                     "check <file>".color("38,139,235"));
 
             println!("{}", "Commands:".color("145,161,2"));
-            println!("      {}    {}\n",
+            println!("      {}    {}",
                      "-V, --version".color("38,139,235"),
                      "Verified the version of the Wave compiler.");
+            println!("      {} Explain an error code in detail.\n",
+                     "--explain <code>".color("38,139,235"));
+
+            println!("{}", "Flags:".color("145,161,2"));
+            println!("      {}    Log each compiler phase while running.",
+                     "-v, --verbose".color("38,139,235"));
+            println!("      {}  Print the parsed AST as JSON.",
+                     "--dump-ast=json".color("38,139,235"));
+            println!("      {}  Print the lexed tokens as JSON without parsing.",
+                     "--dump-tokens=json".color("38,139,235"));
+            println!("      {}  Limit block nesting while parsing (default 256).",
+                     "--max-nesting-depth=<n>".color("38,139,235"));
+            println!("      {}  Stop after this many errors (default 20).",
+                     "--max-errors=<n>".color("38,139,235"));
+            println!("      {}   Color output: always, never or auto (default, honors NO_COLOR).\n",
+                     "--color=<mode>".color("38,139,235"));
             return;
         }
         _ => {
@@ -71,8 +160,130 @@ fn main() {
     }
 }
 
-fn run_wave_file(file_path: &str) {
-    let code = match fs::read_to_string(file_path) {
+fn parse_file_args<'a>(args: &'a [String], usage: &str) -> (&'a str, RunOptions) {
+    let mut file_path = None;
+    let mut options = RunOptions::default();
+    for arg in args {
+        match arg.as_str() {
+            "--dump-ast=json" => options.dump_ast_json = true,
+            "--dump-tokens=json" => options.dump_tokens_json = true,
+            _ if arg.starts_with("--dump-tokens=") => {
+                eprintln!("{} {}",
+                          "Unsupported token dump format:".color("255,71,71"),
+                          &arg["--dump-tokens=".len()..]);
+                process::exit(1);
+            }
+            _ if arg.starts_with("--max-nesting-depth=") => {
+                match arg["--max-nesting-depth=".len()..].parse::<usize>() {
+                    Ok(limit) if limit > 0 => parser::set_max_nesting_depth(limit),
+                    _ => {
+                        eprintln!("{} {}",
+                                  "Invalid nesting depth:".color("255,71,71"),
+                                  arg);
+                        process::exit(1);
+                    }
+                }
+            }
+            _ if arg.starts_with("--max-errors=") => {
+                match arg["--max-errors=".len()..].parse::<usize>() {
+                    Ok(limit) if limit > 0 => parser::set_max_errors(limit),
+                    _ => {
+                        eprintln!("{} {}",
+                                  "Invalid error limit:".color("255,71,71"),
+                                  arg);
+                        process::exit(1);
+                    }
+                }
+            }
+            _ if arg.starts_with("--dump-ast=") => {
+                eprintln!("{} {}",
+                          "Unsupported AST dump format:".color("255,71,71"),
+                          &arg["--dump-ast=".len()..]);
+                process::exit(1);
+            }
+            _ => file_path = Some(arg.as_str()),
+        }
+    }
+
+    let file_path = match file_path {
+        Some(path) => path,
+        None if stdin_is_piped() => "-",
+        None => {
+            eprintln!("{} {}",
+                      "Usage:".color("255,71,71"),
+                      usage);
+            process::exit(1);
+        }
+    };
+
+    (file_path, options)
+}
+
+// Only a pipe or a redirected file counts as piped input. A terminal, /dev/null or
+// the closed stdin of a CI job must not be read as an empty program.
+#[cfg(unix)]
+fn stdin_is_piped() -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    fs::metadata("/dev/stdin")
+        .map(|metadata| metadata.file_type().is_fifo() || metadata.is_file())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn stdin_is_piped() -> bool {
+    use std::io::IsTerminal;
+    !io::stdin().is_terminal()
+}
+
+fn run_wave_file(file_path: &str, options: &RunOptions) {
+    let code = read_wave_source(file_path);
+    let tokens = tokenize_wave_source(&code);
+
+    // Token dumps are for tooling such as highlighters, so stop before parsing
+    if options.dump_tokens_json {
+        print_json(&tokens);
+        return;
+    }
+
+    let ast = parse_wave_tokens(&tokens, &code);
+
+    if options.dump_ast_json {
+        print_json(&ast);
+    }
+}
+
+// Lexes and parses only; code generation is never reached
+fn check_wave_file(file_path: &str) {
+    let code = read_wave_source(file_path);
+    let tokens = tokenize_wave_source(&code);
+    parse_wave_tokens(&tokens, &code);
+
+    let name = if file_path == "-" { "<stdin>" } else { file_path };
+    println!("{} {}",
+             "Check passed:".color("2,161,47"),
+             name);
+}
+
+fn print_json<T: serde::Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(err) => {
+            eprintln!("Error serializing to JSON: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn read_wave_source(file_path: &str) -> String {
+    // `-` reads the program from stdin instead of a file
+    let (file_path, source) = if file_path == "-" {
+        let mut content = String::new();
+        ("<stdin>", io::stdin().read_to_string(&mut content).map(|_| content))
+    } else {
+        (file_path, fs::read_to_string(file_path))
+    };
+
+    let code = match source {
         Ok(content) => content,
         Err(err) => {
             eprintln!("Error reading file {}: {}", file_path, err);
@@ -80,24 +291,81 @@ fn run_wave_file(file_path: &str) {
         }
     };
 
-    let mut lexer = Lexer::new(code.as_str());
+    verbose!("Read {} ({} bytes)", file_path, code.len());
+    code
+}
+
+fn tokenize_wave_source(code: &str) -> Vec<Token> {
+    let mut lexer = Lexer::new(code);
 
     let tokens = lexer.tokenize();
-    eprintln!("Tokens: \n{:#?}", &tokens);
+    if !lexer.errors.is_empty() {
+        report_errors(&lexer.errors);
+    }
+    verbose!("Lexed {} tokens", tokens.len());
+    verbose!("Tokens: \n{:#?}", &tokens);
+    tokens
+}
 
-    let function_name = tokens
+fn parse_wave_tokens(tokens: &[Token], code: &str) -> ASTNode {
+    let name_index = tokens
         .iter()
-        .find(|token| matches!(token.token_type, TokenType::IDENTIFIER(_)))
-        .map(|token| token.lexeme.clone())
+        .position(|token| matches!(token.token_type, TokenType::IDENTIFIER(_)));
+
+    let function_name = name_index
+        .map(|index| tokens[index].lexeme.clone())
+        .unwrap_or_default();
+
+    // Only a `fun name` declaration has type parameters or a doc comment,
+    // a `<` after any other identifier is a comparison
+    let fun_name_index = name_index.filter(|&index| is_function_name(tokens, index));
+
+    let type_params = fun_name_index
+        .map(|index| extract_type_params(tokens, index + 1))
         .unwrap_or_default();
 
-    let params = extract_parameters(&tokens[..], 0, tokens.len());
+    let doc = fun_name_index.and_then(|index| extract_doc_comment(tokens, index - 1));
+
+    let params = extract_parameters(tokens, 0, tokens.len());
 
     let mut peekable_tokens = tokens.iter().peekable();
 
     let body = extract_body(&mut peekable_tokens);
 
-    let ast = function(function_name, params, body);
+    let ast = function(doc, function_name, type_params, params, body);
+
+    if let ASTNode::Function(FunctionNode { body, .. }) = &ast {
+        verbose!("Parsed {} AST nodes", body.len());
+    }
+    verbose!("AST:\n{:#?}", &ast);
+
+    let mut errors = take_errors();
+    errors.extend(validate_loop_control(&ast));
+    if !errors.is_empty() {
+        let errors: Vec<WaveError> = errors
+            .into_iter()
+            .map(|error| {
+                let source_line = code.lines().nth(error.line.saturating_sub(1)).unwrap_or_default();
+                error.with_source_line(source_line)
+            })
+            .collect();
+        report_errors(&errors);
+    }
+    verbose!("Verified loop control statements");
 
-    eprintln!("AST:\n{:#?}", &ast);
+    ast
+}
+
+// Prints at most --max-errors diagnostics and stops the compiler
+fn report_errors(errors: &[WaveError]) -> ! {
+    let limit = parser::max_errors();
+    for error in errors.iter().take(limit) {
+        eprintln!("{}\n", error);
+    }
+    if errors.len() >= limit {
+        eprintln!("{} stopping after {} errors (raise the limit with --max-errors=<n>)",
+                  "error: too many errors,".color("255,71,71"),
+                  limit);
+    }
+    process::exit(1);
 }
\ No newline at end of file
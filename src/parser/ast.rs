@@ -1,11 +1,16 @@
-#[derive(Debug, Clone)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub enum Value {
     Int(i64),
     Float(f64),
     Text(String),
+    Bool(bool),
+    // Anything that is not a single literal, kept as its space separated lexemes
+    Expression(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ASTNode {
     Function(FunctionNode),
     Program(ParameterNode),
@@ -13,39 +18,51 @@ pub enum ASTNode {
     Variable(VariableNode),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FunctionNode {
+    pub doc: Option<String>,
     pub name: String,
+    pub type_params: Vec<String>,
     pub parameters: Vec<ParameterNode>,
     pub body: Vec<ASTNode>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ParameterNode {
     pub name: String,
     pub param_type: String, // For simplicity, assuming type as string.
     pub initial_value: Option<Value>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum StatementNode {
-    Print(String),
-    Println(String),
+    Print { format: String, args: Vec<String> },
+    Println { format: String, args: Vec<String> },
+    Input { format: String, args: Vec<String> },
     Variable(String),
-    If { condition: String, body: Vec<ASTNode> },
+    If {
+        condition: String,
+        body: Vec<ASTNode>,
+        else_if_blocks: Vec<(String, Vec<ASTNode>)>,
+        else_block: Option<Vec<ASTNode>>,
+    },
     For { iterator: String, body: Vec<ASTNode> },
     While { condition: String, body: Vec<ASTNode> },
+    DoWhile { body: Vec<ASTNode>, condition: String },
+    Break { line: usize },
+    Continue { line: usize },
+    Unreachable { line: usize },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariableNode {
     pub name: String,
     pub type_name: String,
-    pub initial_value: Option<String>,
+    pub initial_value: Option<Value>,
 }
 
 /*
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AST {
     pub nodes: Vec<ASTNode>,
 }
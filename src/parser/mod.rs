@@ -1,4 +1,5 @@
 mod parser;
 pub mod ast;
+pub mod verification;
 
 pub use crate::parser::parser::*;
\ No newline at end of file
@@ -1,17 +1,75 @@
+use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 use std::iter::Peekable;
 use std::slice::Iter;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::error::{WaveError, WaveErrorKind};
 use crate::lexer::*;
 use crate::parser::ast::*;
 
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 256;
+pub const DEFAULT_MAX_ERRORS: usize = 20;
+
+static MAX_NESTING_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_NESTING_DEPTH);
+static MAX_ERRORS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_ERRORS);
+
+pub fn set_max_nesting_depth(limit: usize) {
+    MAX_NESTING_DEPTH.store(limit, Ordering::Relaxed);
+}
+
+pub fn set_max_errors(limit: usize) {
+    MAX_ERRORS.store(limit, Ordering::Relaxed);
+}
+
+pub fn max_errors() -> usize {
+    MAX_ERRORS.load(Ordering::Relaxed)
+}
+
+// Parse state is per thread, so parallel parses never share a depth counter or errors.
+// Errors are collected instead of aborting, so one run reports every problem in the file
+thread_local! {
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static ERRORS: RefCell<Vec<WaveError>> = const { RefCell::new(Vec::new()) };
+}
+
+// Repeated reports of the same problem count once, and nothing is recorded past the limit
+fn report(error: WaveError) {
+    ERRORS.with(|errors| {
+        let mut errors = errors.borrow_mut();
+        let duplicate = errors.iter().any(|known| {
+            known.kind == error.kind && known.line == error.line && known.message == error.message
+        });
+        if !duplicate && errors.len() < max_errors() {
+            errors.push(error);
+        }
+    });
+}
+
+// Parsing stops early once the error limit is reached, the rest would only be cascades
+fn too_many_errors() -> bool {
+    ERRORS.with(|errors| errors.borrow().len() >= max_errors())
+}
+
+fn syntax_error(message: impl Into<String>, token: Option<&Token>) {
+    let line = token.map(|t| t.line).unwrap_or_default();
+    report(WaveError::new(WaveErrorKind::SyntaxError, message, line));
+}
+
+// Drains the errors recorded since the last call
+pub fn take_errors() -> Vec<WaveError> {
+    ERRORS.with(|errors| errors.take())
+}
+
 pub fn parse(tokens: &[Token]) -> Option<ASTNode> {
     let mut tokens_iter = tokens.iter().peekable();
     parse_function(&mut tokens_iter)
 }
 
-pub fn function(function_name: String, parameters: Vec<ParameterNode>, body: Vec<ASTNode>) -> ASTNode {
+pub fn function(doc: Option<String>, function_name: String, type_params: Vec<String>, parameters: Vec<ParameterNode>, body: Vec<ASTNode>) -> ASTNode {
     ASTNode::Function(FunctionNode {
+        doc,
         name: function_name,
+        type_params,
         parameters, // No parameters
         body,       // Empty body
     })
@@ -25,6 +83,54 @@ pub fn param(parameter: String, param_type: String, initial_value: Option<Value>
     }
 }
 
+// Joins the `///` lines directly above the declaration token at `index`
+pub fn extract_doc_comment(tokens: &[Token], index: usize) -> Option<String> {
+    let lines: Vec<&str> = tokens[..index]
+        .iter()
+        .rev()
+        .map_while(|token| match &token.token_type {
+            TokenType::DocComment(doc) => Some(doc.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+    Some(lines.into_iter().rev().collect::<Vec<_>>().join("\n"))
+}
+
+// True when the identifier at `index` is the name in a `fun name` declaration
+pub fn is_function_name(tokens: &[Token], index: usize) -> bool {
+    index > 0
+        && tokens[index - 1].token_type == TokenType::FUN
+        && matches!(tokens[index].token_type, TokenType::IDENTIFIER(_))
+}
+
+// Generic type parameters: fun id<T, U>(...)
+// Only parsed for now, monomorphization is not implemented yet
+pub fn extract_type_params(tokens: &[Token], start: usize) -> Vec<String> {
+    let mut type_params = vec![];
+
+    if !matches!(tokens.get(start).map(|t| &t.token_type), Some(TokenType::LCHEVR)) {
+        return type_params;
+    }
+
+    for token in &tokens[start + 1..] {
+        match &token.token_type {
+            TokenType::IDENTIFIER(name) => type_params.push(name.clone()),
+            TokenType::COMMA => {}
+            TokenType::RCHEVR => break,
+            _ => {
+                syntax_error(format!("expected type parameter name, but got '{}'", token.lexeme), Some(token));
+                break;
+            }
+        }
+    }
+
+    type_params
+}
+
 pub fn extract_parameters(tokens: &[Token], start: usize, end: usize) -> Vec<ParameterNode> {
     let mut params = vec![];
     let mut i = start;
@@ -52,7 +158,7 @@ pub fn extract_parameters(tokens: &[Token], start: usize, end: usize) -> Vec<Par
             j += 1;
 
             let param_type = match &tokens[j].token_type {
-                TokenType::TypeInt(_) => tokens[j].lexeme.clone(),
+                TokenType::TypeInt(_) | TokenType::TypeFloat(_) | TokenType::IDENTIFIER(_) => tokens[j].lexeme.clone(),
                 _ => "unknown".into(),
             };
             j += 1;
@@ -63,6 +169,8 @@ pub fn extract_parameters(tokens: &[Token], start: usize, end: usize) -> Vec<Par
                     match &tokens[j].token_type {
                         TokenType::FLOAT(value) => Some(Value::Float(*value)),
                         TokenType::NUMBER(value) => Some(Value::Int(*value)),
+                        TokenType::TRUE => Some(Value::Bool(true)),
+                        TokenType::FALSE => Some(Value::Bool(false)),
                         _ => None,
                     }
                 } else {
@@ -96,47 +204,37 @@ pub fn extract_body<'a>(tokens: &mut Peekable<Iter<'a, Token>>) -> Vec<ASTNode>
     let mut body = vec![];
 
     while let Some(token) = tokens.next() {
-        match &token.token_type {
-            TokenType::EOF => break,
-            TokenType::VAR => {
-                if let Some(ast_node) = parse_var(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            TokenType::PRINTLN => {
-                if let Some(ast_node) = parse_println(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            TokenType::PRINT => {
-                if let Some(ast_node) = parse_print(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            TokenType::IF => {
-                if let Some(ast_node) = parse_if(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            TokenType::FOR => {
-                if let Some(ast_node) = parse_for(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            TokenType::WHILE => {
-                if let Some(ast_node) = parse_while(tokens) {
-                    body.push(ast_node);
-                }
-            }
-            _ => {
-                // Ignore unprocessed tokens
-            }
+        if token.token_type == TokenType::EOF || too_many_errors() {
+            break;
+        }
+        if let Some(ast_node) = parse_statement(token, tokens) {
+            body.push(ast_node);
         }
     }
 
     body
 }
 
+// Parses the statement that starts with the already consumed `token`
+fn parse_statement(token: &Token, tokens: &mut Peekable<Iter<'_, Token>>) -> Option<ASTNode> {
+    match &token.token_type {
+        TokenType::VAR => parse_var(tokens),
+        TokenType::PRINTLN => parse_println(tokens),
+        TokenType::PRINT => parse_print(tokens),
+        TokenType::INPUT => parse_input(tokens),
+        TokenType::IF => parse_if(tokens),
+        TokenType::FOR => parse_for(tokens),
+        TokenType::WHILE => parse_while(tokens),
+        TokenType::DO => parse_do_while(tokens),
+        TokenType::BREAK | TokenType::CONTINUE => parse_loop_control(token, tokens),
+        TokenType::UNREACHABLE => parse_unreachable(token, tokens),
+        _ => {
+            // Ignore unprocessed tokens
+            None
+        }
+    }
+}
+
 // FUN parsing
 fn parse_function(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
     tokens.next();
@@ -171,7 +269,7 @@ fn parse_function(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
     let param_names: HashSet<String> = parameters.iter().map(|p| p.name.clone()).collect();
     for param in &parameters {
         if param_names.contains(&param.name) {
-            println!("Error: Parameter '{}' is declared multiple times", param.name);
+            eprintln!("Error: Parameter '{}' is declared multiple times", param.name);
             return None;
         }
     }
@@ -181,74 +279,65 @@ fn parse_function(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
     }
 
     let body = extract_body(tokens);
-    Some(function(name, parameters, body))
+    Some(function(None, name, vec![], parameters, body))
 }
 
 // VAR parsing
 fn parse_var(tokens: &mut Peekable<Iter<'_, Token>>) -> Option<ASTNode> {
-    println!("Starting parse_var...");
+    verbose!("Starting parse_var...");
 
-    let name = match tokens.next() {
+    // var imm name: type declares an immutable variable
+    if let Some(Token { token_type: TokenType::IMM, .. }) = tokens.peek() {
+        tokens.next();
+    }
+
+    let token = tokens.next();
+    let name = match token {
         Some(Token { token_type: TokenType::IDENTIFIER(name), .. }) => name.clone(),
         _ => {
-            println!("Expected identifier");
+            syntax_error("expected identifier after 'var'", token);
             return None;
         }
     };
 
-    if !matches!(tokens.next().map(|t| &t.token_type), Some(TokenType::COLON)) {
-        println!("Expected ':' after identifier");
+    let token = tokens.next();
+    if !matches!(token.map(|t| &t.token_type), Some(TokenType::COLON)) {
+        syntax_error(format!("expected ':' after '{}'", name), token);
         return None;
     }
 
     let type_name = match tokens.next() {
         Some(Token { lexeme, .. }) => lexeme.clone(),
-        _ => {
-            println!("Expected type after ':'");
+        None => {
+            syntax_error("expected type after ':'", token);
             return None;
         }
     };
 
-    let initial_value = if let Some(Token { token_type: TokenType::EQUAL, .. }) = tokens.peek() {
-        tokens.next();
-        match tokens.next() {
-            Some(Token { lexeme, .. }) => Some(lexeme.clone()),
-            _ => None,
-        }
-    } else {
-        None
-    };
+    let equal = tokens.next_if(|token| token.token_type == TokenType::EQUAL);
 
-    let mut param_tokens = vec![];
-    let mut paren_depth = 1;
-    while let Some(token) = tokens.next() {
-        match token.token_type {
-            TokenType::LPAREN => paren_depth += 1,
-            TokenType::RPAREN => {
-                paren_depth -= 1;
-                if paren_depth == 0 {
-                    break;
-                }
-            }
-            _ => {}
+    // The initializer runs up to ';', which is consumed as well
+    let mut initializer = vec![];
+    for token in tokens.by_ref() {
+        if matches!(token.token_type, TokenType::SEMICOLON | TokenType::EOF) {
+            break;
+        }
+        if equal.is_some() {
+            initializer.push(token);
         }
-        param_tokens.push(token.clone());
     }
 
-    let parameters: Vec<ParameterNode> = vec![];
-
-    let mut param_names: HashSet<String> = HashSet::new();
-    for param in parameters.iter() {
-        if !param_names.insert(param.name.clone()) {
-            println!("Error: Parameter '{}' is declared multiple times", param.name);
+    let initial_value = match (equal, &initializer[..]) {
+        (None, _) => None,
+        (Some(equal), []) => {
+            syntax_error(format!("expected a value after '=' for '{}'", name), Some(equal));
             return None;
         }
-    }
-
-
-    if let Some(Token { token_type: TokenType::SEMICOLON, .. }) = tokens.peek() {
-        tokens.next();
-    }
+        (Some(_), [token]) => Some(literal_value(token)),
+        (Some(_), tokens) => Some(Value::Expression(
+            tokens.iter().map(|t| t.lexeme.as_str()).collect::<Vec<_>>().join(" "),
+        )),
+    };
 
     Some(ASTNode::Variable(VariableNode {
         name,
@@ -257,122 +346,433 @@ fn parse_var(tokens: &mut Peekable<Iter<'_, Token>>) -> Option<ASTNode> {
     }))
 }
 
+// Single token initializers become typed literals, true and false are keywords rather than names
+fn literal_value(token: &Token) -> Value {
+    match &token.token_type {
+        TokenType::NUMBER(value) => Value::Int(*value),
+        TokenType::FLOAT(value) => Value::Float(*value),
+        TokenType::STRING(value) => Value::Text(value.clone()),
+        TokenType::TRUE => Value::Bool(true),
+        TokenType::FALSE => Value::Bool(false),
+        _ => Value::Expression(token.lexeme.clone()),
+    }
+}
+
 // PRINTLN parsing
-fn parse_println<'a, T: Iterator<Item=&'a Token>>(tokens: &mut Peekable<T>) -> Option<ASTNode> {
-    let token = tokens.peek()?; // talkens.peek() returns Option<&Token>
+fn parse_println(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    let (format, args) = parse_format_call("println", tokens)?;
+    Some(ASTNode::Statement(StatementNode::Println { format, args }))
+}
 
-    if token.token_type != TokenType::PRINTLN {
-        println!("Error: Expected 'println'");
-        return None;
-    }
-    tokens.next(); // Consume PRINTLN
+// PRINT parsing
+fn parse_print(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    let (format, args) = parse_format_call("print", tokens)?;
+    Some(ASTNode::Statement(StatementNode::Print { format, args }))
+}
 
-    if tokens.peek()?.token_type != TokenType::LPAREN {
-        println!("Error: Expected '(' after 'println'");
+// INPUT parsing
+fn parse_input(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    let (format, args) = parse_format_call("input", tokens)?;
+    Some(ASTNode::Statement(StatementNode::Input { format, args }))
+}
+
+// Shared by print, println and input: ("format {}", arg, ...);
+// The keyword itself has already been consumed by the caller
+fn parse_format_call(name: &str, tokens: &mut Peekable<Iter<Token>>) -> Option<(String, Vec<String>)> {
+    let token = tokens.next();
+    if !matches!(token.map(|t| &t.token_type), Some(TokenType::LPAREN)) {
+        syntax_error(format!("expected '(' after '{}'", name), token);
         return None;
     }
-    tokens.next(); // Consume '('
 
-    let content = if let Some(Token { token_type: TokenType::STRING(content), .. }) = tokens.next() {
-        content.clone() // Need clone() because it is String
-    } else {
-        println!("Error: Expected string literal in 'println'");
-        return None;
+    let format_token = tokens.next();
+    let format = match format_token {
+        Some(Token { token_type: TokenType::STRING(format), .. }) => format.clone(),
+        _ => {
+            syntax_error(format!("expected string literal in '{}'", name), format_token);
+            return None;
+        }
     };
 
-    if tokens.peek()?.token_type != TokenType::RPAREN {
-        println!("Error: Expected closing ')'");
+    let mut args = vec![];
+    loop {
+        let token = tokens.next();
+        match token.map(|t| &t.token_type) {
+            Some(TokenType::RPAREN) => break,
+            Some(TokenType::COMMA) => {
+                let mut lexemes = vec![];
+                let mut paren_depth = 0;
+                while let Some(token) = tokens.peek() {
+                    match token.token_type {
+                        TokenType::COMMA | TokenType::RPAREN if paren_depth == 0 => break,
+                        TokenType::LPAREN => paren_depth += 1,
+                        TokenType::RPAREN => paren_depth -= 1,
+                        TokenType::SEMICOLON | TokenType::EOF => break,
+                        _ => {}
+                    }
+                    lexemes.push(token.lexeme.clone());
+                    tokens.next();
+                }
+                if lexemes.is_empty() {
+                    syntax_error(format!("expected argument after ',' in '{}'", name), token);
+                    return None;
+                }
+                args.push(lexemes.join(" "));
+            }
+            _ => {
+                syntax_error(format!("expected ',' or ')' in '{}'", name), token);
+                return None;
+            }
+        }
+    }
+
+    if let Some(Token { token_type: TokenType::SEMICOLON, .. }) = tokens.peek() {
+        tokens.next();
+    }
+
+    let placeholder_count = format.matches("{}").count();
+    if placeholder_count != args.len() {
+        report(WaveError::new(
+            WaveErrorKind::FormatArgumentMismatch,
+            format!(
+                "'{}' format string has {} placeholder(s) but {} argument(s) were given",
+                name,
+                placeholder_count,
+                args.len()
+            ),
+            format_token.map(|t| t.line).unwrap_or_default(),
+        ));
         return None;
     }
-    tokens.next(); // Consume ')'
 
-    Some(ASTNode::Statement(StatementNode::Println(content)))
+    Some((format, args))
 }
 
-// PRINT parsing
-fn parse_print(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
-    if let Some(Token { token_type: TokenType::LPAREN, .. }) = tokens.next() {
-        if let Some(Token { token_type: TokenType::STRING(ref content), .. }) = tokens.next() {
-            if let Some(Token { token_type: TokenType::RPAREN, .. }) = tokens.next() {
-                return Some(ASTNode::Statement(StatementNode::Print(content.clone())));
+// IF parsing
+fn parse_if(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    let condition = parse_condition(tokens)?;
+    let body = parse_block(tokens)?;
+
+    let mut else_if_blocks = vec![];
+    let mut else_block = None;
+
+    loop {
+        match tokens.peek().map(|t| &t.token_type) {
+            // elif (...) { } is sugar for else if (...) { }
+            Some(TokenType::ELIF) => {
+                tokens.next();
+                let else_if_condition = parse_condition(tokens)?;
+                let else_if_body = parse_block(tokens)?;
+                else_if_blocks.push((else_if_condition, else_if_body));
+            }
+            Some(TokenType::ELSE) => {
+                tokens.next();
+                if let Some(Token { token_type: TokenType::IF, .. }) = tokens.peek() {
+                    tokens.next();
+                    let else_if_condition = parse_condition(tokens)?;
+                    let else_if_body = parse_block(tokens)?;
+                    else_if_blocks.push((else_if_condition, else_if_body));
+                } else {
+                    else_block = Some(parse_block(tokens)?);
+                    break;
+                }
             }
+            _ => break,
         }
     }
-    None
+
+    Some(ASTNode::Statement(StatementNode::If {
+        condition,
+        body,
+        else_if_blocks,
+        else_block,
+    }))
 }
 
-// IF parsing
-fn parse_if(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
-    if let Some(Token { token_type: TokenType::LPAREN, .. }) = tokens.next() {
-        // Condition extraction (simple handling)
-        let condition = if let Some(Token { lexeme, .. }) = tokens.next() {
-            lexeme.clone()
-        } else {
-            return None;
-        };
+// Condition parsing: ( ... ), kept as the space separated lexemes
+fn parse_condition(tokens: &mut Peekable<Iter<Token>>) -> Option<String> {
+    let open = tokens.next();
+    if !matches!(open.map(|t| &t.token_type), Some(TokenType::LPAREN)) {
+        syntax_error("expected '(' before condition", open);
+        return None;
+    }
 
-        if let Some(Token { token_type: TokenType::RPAREN, .. }) = tokens.next() {
-            let body = parse_block(tokens)?;
-            return Some(ASTNode::Statement(StatementNode::If { condition, body }));
+    let mut lexemes = vec![];
+    let mut paren_depth = 1;
+    for token in tokens.by_ref() {
+        match token.token_type {
+            TokenType::LPAREN => paren_depth += 1,
+            TokenType::RPAREN => {
+                paren_depth -= 1;
+                if paren_depth == 0 {
+                    return Some(lexemes.join(" "));
+                }
+            }
+            _ => {}
         }
+        lexemes.push(token.lexeme.clone());
     }
+
+    syntax_error("expected ')' after condition", open);
     None
 }
 
 // FOR parsing
 fn parse_for(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
-    if let Some(Token { token_type: TokenType::LPAREN, .. }) = tokens.next() {
-        let iterator = if let Some(Token { lexeme, .. }) = tokens.next() {
-            lexeme.clone()
-        } else {
-            return None;
-        };
-
-        if let Some(Token { token_type: TokenType::RPAREN, .. }) = tokens.next() {
-            let body = parse_block(tokens)?;
-            return Some(ASTNode::Statement(StatementNode::For { iterator, body }));
-        }
-    }
-    None
+    let iterator = parse_condition(tokens)?;
+    let body = parse_block(tokens)?;
+    Some(ASTNode::Statement(StatementNode::For { iterator, body }))
 }
 
 // WHILE parsing
 fn parse_while(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
-    if let Some(Token { token_type: TokenType::LPAREN, .. }) = tokens.next() {
-        let condition = if let Some(Token { lexeme, .. }) = tokens.next() {
-            lexeme.clone()
-        } else {
-            return None;
-        };
+    let condition = parse_condition(tokens)?;
+    let body = parse_block(tokens)?;
+    Some(ASTNode::Statement(StatementNode::While { condition, body }))
+}
 
-        if let Some(Token { token_type: TokenType::RPAREN, .. }) = tokens.next() {
-            let body = parse_block(tokens)?;
-            return Some(ASTNode::Statement(StatementNode::While { condition, body }));
-        }
+// DO WHILE parsing
+fn parse_do_while(tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    let body = parse_block(tokens)?;
+
+    let token = tokens.next();
+    if !matches!(token.map(|t| &t.token_type), Some(TokenType::WHILE)) {
+        syntax_error("expected 'while' after 'do' block", token);
+        return None;
     }
-    None
+    let condition = parse_condition(tokens)?;
+
+    if let Some(Token { token_type: TokenType::SEMICOLON, .. }) = tokens.peek() {
+        tokens.next();
+    }
+
+    Some(ASTNode::Statement(StatementNode::DoWhile { body, condition }))
+}
+
+// BREAK / CONTINUE parsing
+fn parse_loop_control(token: &Token, tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    if let Some(Token { token_type: TokenType::SEMICOLON, .. }) = tokens.peek() {
+        tokens.next();
+    }
+
+    let statement = match token.token_type {
+        TokenType::BREAK => StatementNode::Break { line: token.line },
+        _ => StatementNode::Continue { line: token.line },
+    };
+    Some(ASTNode::Statement(statement))
+}
+
+// UNREACHABLE parsing
+// Marks a path that can never be taken, so the backend may emit a terminator there
+fn parse_unreachable(token: &Token, tokens: &mut Peekable<Iter<Token>>) -> Option<ASTNode> {
+    if let Some(Token { token_type: TokenType::SEMICOLON, .. }) = tokens.peek() {
+        tokens.next();
+    }
+
+    Some(ASTNode::Statement(StatementNode::Unreachable { line: token.line }))
 }
 
 // block parsing
+// Blocks are the only recursive construct, so the nesting guard lives here
 fn parse_block(tokens: &mut Peekable<Iter<Token>>) -> Option<Vec<ASTNode>> {
-    if let Some(Token { token_type: TokenType::LBRACE, .. }) = tokens.next() {
-        let mut body = vec![];
+    let depth = NESTING_DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    });
+    let limit = MAX_NESTING_DEPTH.load(Ordering::Relaxed);
+    let body = if depth > limit {
+        let line = tokens.peek().map(|t| t.line).unwrap_or_default();
+        report(WaveError::new(
+            WaveErrorKind::NestingTooDeep,
+            format!("blocks are nested more than {} levels deep", limit),
+            line,
+        ));
+        skip_block(tokens);
+        None
+    } else {
+        parse_block_body(tokens)
+    };
+    NESTING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    body
+}
 
-        while let Some(token) = tokens.peek() {
-            if let TokenType::RBRACE = token.token_type {
-                tokens.next(); // } consumption
-                break;
+// Consumes a whole { ... } block, so its inner blocks do not report the same error again
+fn skip_block(tokens: &mut Peekable<Iter<Token>>) {
+    let mut brace_depth = 0;
+    for token in tokens.by_ref() {
+        match token.token_type {
+            TokenType::LBRACE => brace_depth += 1,
+            TokenType::RBRACE => {
+                brace_depth -= 1;
+                if brace_depth <= 0 {
+                    break;
+                }
             }
+            TokenType::EOF => break,
+            _ => {}
+        }
+    }
+}
+
+fn parse_block_body(tokens: &mut Peekable<Iter<Token>>) -> Option<Vec<ASTNode>> {
+    let open = tokens.next();
+    if let Some(Token { token_type: TokenType::LBRACE, .. }) = open {
+        let mut body = vec![];
 
-            body.extend(extract_body(tokens)); // The part that I modified here
+        while let Some(token) = tokens.next() {
+            if too_many_errors() {
+                return None;
+            }
+            match token.token_type {
+                TokenType::RBRACE => return Some(body), // } consumption
+                TokenType::EOF => break,
+                _ => {
+                    if let Some(ast_node) = parse_statement(token, tokens) {
+                        body.push(ast_node);
+                    }
+                }
+            }
         }
 
-        return Some(body);
+        syntax_error("expected '}' to close block", open);
+        return None;
     }
+    syntax_error("expected '{' to start block", open);
     None
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_errors(source: &str) -> Vec<WaveErrorKind> {
+        let tokens = Lexer::new(source).tokenize();
+        extract_body(&mut tokens.iter().peekable());
+        take_errors().into_iter().map(|error| error.kind).collect()
+    }
+
+    fn parse_body(source: &str) -> Vec<ASTNode> {
+        let tokens = Lexer::new(source).tokenize();
+        let body = extract_body(&mut tokens.iter().peekable());
+        assert!(take_errors().is_empty(), "{}", source);
+        body
+    }
+
+    #[test]
+    fn elif_and_else_if_chain() {
+        let body = parse_body(r#"
+            if (a == 1) {
+                println("one");
+            } elif (a == 2) {
+                println("two");
+            } else if (a == 3) {
+                println("three");
+            } else {
+                println("other");
+            }
+        "#);
+
+        match &body[..] {
+            [ASTNode::Statement(StatementNode::If { condition, else_if_blocks, else_block, .. })] => {
+                assert_eq!(condition, "a == 1");
+                assert_eq!(else_if_blocks.len(), 2);
+                assert_eq!(else_if_blocks[0].0, "a == 2");
+                assert_eq!(else_if_blocks[1].0, "a == 3");
+                assert!(else_block.is_some());
+            }
+            other => panic!("expected a single if statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bool_keywords_initialize_typed_values() {
+        let body = parse_body("var done: bool = true;\nvar failed: bool = false;\nvar count: i32 = n + 1;");
+
+        let values: Vec<_> = body
+            .iter()
+            .map(|node| match node {
+                ASTNode::Variable(VariableNode { initial_value, .. }) => initial_value.clone(),
+                other => panic!("expected a variable, got {:?}", other),
+            })
+            .collect();
+        assert!(matches!(values[0], Some(Value::Bool(true))));
+        assert!(matches!(values[1], Some(Value::Bool(false))));
+        assert!(matches!(&values[2], Some(Value::Expression(text)) if text == "n + 1"));
+    }
+
+    #[test]
+    fn unreachable_statement() {
+        let body = parse_body("if (done) { return; } else { unreachable; }");
+
+        match &body[..] {
+            [ASTNode::Statement(StatementNode::If { else_block: Some(else_body), .. })] => {
+                assert!(matches!(else_body[..], [ASTNode::Statement(StatementNode::Unreachable { line: 1 })]));
+            }
+            other => panic!("expected a single if statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn format_placeholders_match_arguments() {
+        for name in ["print", "println", "input"] {
+            let source = format!("{}(\"{{}} and {{}}\", a, b);", name);
+            assert!(parse_errors(&source).is_empty(), "{}", source);
+        }
+    }
+
+    #[test]
+    fn format_placeholder_mismatch_is_an_error() {
+        for name in ["print", "println", "input"] {
+            for source in [
+                format!("{}(\"{{}}\");", name),
+                format!("{}(\"{{}}\", a, b);", name),
+                format!("{}(\"none\", a);", name),
+            ] {
+                assert_eq!(parse_errors(&source), vec![WaveErrorKind::FormatArgumentMismatch], "{}", source);
+            }
+        }
+    }
+
+    #[test]
+    fn type_params_follow_a_function_name() {
+        let tokens = Lexer::new("fun id<T, U>(a: T) { }").tokenize();
+        assert!(is_function_name(&tokens, 1));
+        assert_eq!(extract_type_params(&tokens, 2), vec!["T", "U"]);
+        assert!(take_errors().is_empty());
+    }
+
+    #[test]
+    fn leading_comparison_is_not_a_type_param_list() {
+        let tokens = Lexer::new("while (i < 3) { i++; }").tokenize();
+        let name_index = tokens
+            .iter()
+            .position(|token| matches!(token.token_type, TokenType::IDENTIFIER(_)))
+            .unwrap();
+        assert!(!is_function_name(&tokens, name_index));
+
+        extract_body(&mut tokens.iter().peekable());
+        assert!(take_errors().is_empty());
+    }
+
+    #[test]
+    fn nesting_past_the_limit_is_one_error() {
+        let depth = DEFAULT_MAX_NESTING_DEPTH + 1;
+        let source = "if (a) {\n".repeat(depth) + "println(\"deep\");\n" + &"}\n".repeat(depth);
+        assert_eq!(parse_errors(&source), vec![WaveErrorKind::NestingTooDeep]);
+
+        let source = "if (a) {\n".repeat(depth - 1) + &"}\n".repeat(depth - 1);
+        assert!(parse_errors(&source).is_empty());
+    }
+
+    #[test]
+    fn errors_stop_at_the_limit() {
+        let source = "var : i32;\n".repeat(DEFAULT_MAX_ERRORS + 10);
+        assert_eq!(parse_errors(&source).len(), DEFAULT_MAX_ERRORS);
+    }
+}
+
 /*
 use crate::lexer::{FloatType, IntegerType, Lexer, Token, TokenType};
 use crate::parser::ast::{AST, ASTNode, Value};
@@ -0,0 +1,89 @@
+use crate::error::{WaveError, WaveErrorKind};
+use crate::parser::ast::*;
+
+// Checks that every break/continue is nested inside a for, while or do-while loop
+pub fn validate_loop_control(ast: &ASTNode) -> Vec<WaveError> {
+    let mut errors = vec![];
+    visit_node(ast, 0, &mut errors);
+    errors
+}
+
+fn visit_nodes(nodes: &[ASTNode], loop_depth: usize, errors: &mut Vec<WaveError>) {
+    for node in nodes {
+        visit_node(node, loop_depth, errors);
+    }
+}
+
+fn visit_node(node: &ASTNode, loop_depth: usize, errors: &mut Vec<WaveError>) {
+    match node {
+        // A function body starts outside of any loop, even when nested in one
+        ASTNode::Function(function) => visit_nodes(&function.body, 0, errors),
+        ASTNode::Statement(statement) => match statement {
+            StatementNode::If { body, else_if_blocks, else_block, .. } => {
+                visit_nodes(body, loop_depth, errors);
+                for (_, else_if_body) in else_if_blocks {
+                    visit_nodes(else_if_body, loop_depth, errors);
+                }
+                if let Some(else_body) = else_block {
+                    visit_nodes(else_body, loop_depth, errors);
+                }
+            }
+            StatementNode::For { body, .. }
+            | StatementNode::While { body, .. }
+            | StatementNode::DoWhile { body, .. } => {
+                visit_nodes(body, loop_depth + 1, errors);
+            }
+            StatementNode::Break { line } if loop_depth == 0 => {
+                errors.push(WaveError::new(
+                    WaveErrorKind::LoopControlOutsideLoop,
+                    "'break' can only be used inside a loop",
+                    *line,
+                ));
+            }
+            StatementNode::Continue { line } if loop_depth == 0 => {
+                errors.push(WaveError::new(
+                    WaveErrorKind::LoopControlOutsideLoop,
+                    "'continue' can only be used inside a loop",
+                    *line,
+                ));
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::{extract_body, function};
+
+    fn loop_control_errors(source: &str) -> Vec<(WaveErrorKind, usize)> {
+        let tokens = Lexer::new(source).tokenize();
+        let body = extract_body(&mut tokens.iter().peekable());
+        let ast = function(None, "main".to_string(), vec![], vec![], body);
+        validate_loop_control(&ast)
+            .into_iter()
+            .map(|error| (error.kind, error.line))
+            .collect()
+    }
+
+    #[test]
+    fn break_at_function_level() {
+        let errors = loop_control_errors("println(\"start\");\nbreak;");
+        assert_eq!(errors, vec![(WaveErrorKind::LoopControlOutsideLoop, 2)]);
+    }
+
+    #[test]
+    fn continue_after_a_loop() {
+        let errors = loop_control_errors("while (i < 3) {\n    i++;\n}\ncontinue;");
+        assert_eq!(errors, vec![(WaveErrorKind::LoopControlOutsideLoop, 4)]);
+    }
+
+    #[test]
+    fn break_inside_do_while() {
+        let errors = loop_control_errors("do {\n    if (i == 3) {\n        break;\n    }\n} while (i < 10);");
+        assert!(errors.is_empty());
+    }
+}